@@ -0,0 +1,155 @@
+#[cfg(windows)]
+mod adl;
+#[cfg(windows)]
+use adl::AdlApi;
+
+/// Real-time AMD GPU metrics, harvested without any vendor SDK dependency beyond what
+/// the platform already provides (ADL on Windows, amdgpu sysfs on Linux). Fields are
+/// optional because availability varies by driver version and card generation.
+#[derive(Debug, Clone, Default)]
+pub struct AmdGpuUsage {
+    pub utilization_percent: Option<f64>,
+    pub temperature_c: Option<f64>,
+    pub memory_used_bytes: Option<u64>,
+}
+
+/// Queries AMD-specific metrics for the adapter at `adapter_index`, in ADL's own
+/// (AMD-only) enumeration order — callers must track that separately from any DXGI
+/// index. Returns `None` if no AMD backend is available on this platform/driver.
+#[cfg(windows)]
+pub fn query_usage(adapter_index: usize) -> Option<AmdGpuUsage> {
+    unsafe { AdlApi::load()?.current_status(adapter_index as i32) }
+}
+
+#[cfg(target_os = "linux")]
+pub fn query_usage(adapter_index: usize) -> Option<AmdGpuUsage> {
+    let device_dir = format!("/sys/class/drm/card{adapter_index}/device");
+
+    let utilization_percent = read_u64(&format!("{device_dir}/gpu_busy_percent")).map(|v| v as f64);
+    let memory_used_bytes = read_u64(&format!("{device_dir}/mem_info_vram_used"));
+    let temperature_c = read_hwmon_temperature(&device_dir);
+
+    if utilization_percent.is_none() && memory_used_bytes.is_none() && temperature_c.is_none() {
+        return None;
+    }
+
+    Some(AmdGpuUsage {
+        utilization_percent,
+        temperature_c,
+        memory_used_bytes,
+    })
+}
+
+#[cfg(target_os = "linux")]
+const AMD_VENDOR_ID: u64 = 0x1002;
+
+/// Finds every amdgpu card under `/sys/class/drm` (identified by `device/vendor`) and
+/// pairs its DRM card index with whatever metrics `query_usage` can read for it. This is
+/// the Linux entry point `get_gpu_stats` folds in alongside NVML, since amdgpu sysfs
+/// nodes carry no adapter name of their own.
+#[cfg(target_os = "linux")]
+pub fn enumerate_linux() -> Vec<(usize, AmdGpuUsage)> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let index: usize = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("card")?
+                .parse()
+                .ok()?;
+            let vendor_path = entry.path().join("device/vendor");
+            let vendor = read_u64(vendor_path.to_str()?)?;
+            (vendor == AMD_VENDOR_ID).then_some(())?;
+            query_usage(index).map(|usage| (index, usage))
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read_u64(path: &str) -> Option<u64> {
+    let raw = std::fs::read_to_string(path).ok()?;
+    let raw = raw.trim();
+    match raw.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => raw.parse().ok(),
+    }
+}
+
+// amdgpu exposes temperature under a hwmon subdirectory whose numeric suffix isn't
+// stable across boots, so the first (and usually only) entry is taken as-is.
+#[cfg(target_os = "linux")]
+fn read_hwmon_temperature(device_dir: &str) -> Option<f64> {
+    let hwmon_dir = std::fs::read_dir(format!("{device_dir}/hwmon"))
+        .ok()?
+        .find_map(|entry| entry.ok())?
+        .path();
+    let millidegrees = read_u64(hwmon_dir.join("temp1_input").to_str()?)?;
+    Some(millidegrees as f64 / 1000.0)
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn query_usage(_adapter_index: usize) -> Option<AmdGpuUsage> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn read_u64_parses_decimal() {
+        let dir = std::env::temp_dir().join("nanai_consys_test_read_u64_decimal");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("value");
+        fs::write(&path, "1234\n").unwrap();
+
+        assert_eq!(read_u64(path.to_str().unwrap()), Some(1234));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_u64_parses_hex() {
+        let dir = std::env::temp_dir().join("nanai_consys_test_read_u64_hex");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("value");
+        fs::write(&path, "0x1002\n").unwrap();
+
+        assert_eq!(read_u64(path.to_str().unwrap()), Some(0x1002));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_u64_missing_file_returns_none() {
+        let path = std::env::temp_dir().join("nanai_consys_test_read_u64_missing/value");
+        assert_eq!(read_u64(path.to_str().unwrap()), None);
+    }
+
+    #[test]
+    fn read_hwmon_temperature_reads_first_hwmon_entry() {
+        let device_dir = std::env::temp_dir().join("nanai_consys_test_hwmon_device");
+        let hwmon_dir = device_dir.join("hwmon/hwmon3");
+        fs::create_dir_all(&hwmon_dir).unwrap();
+        fs::write(hwmon_dir.join("temp1_input"), "45678\n").unwrap();
+
+        assert_eq!(
+            read_hwmon_temperature(device_dir.to_str().unwrap()),
+            Some(45.678)
+        );
+
+        fs::remove_dir_all(&device_dir).unwrap();
+    }
+
+    #[test]
+    fn read_hwmon_temperature_missing_hwmon_dir_returns_none() {
+        let device_dir = std::env::temp_dir().join("nanai_consys_test_hwmon_missing_device");
+        assert_eq!(read_hwmon_temperature(device_dir.to_str().unwrap()), None);
+    }
+}
@@ -0,0 +1,106 @@
+use windows::core::PCSTR;
+use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryA};
+use std::ffi::c_void;
+
+use super::AmdGpuUsage;
+
+// Unlike NVAPI, ADL's entry points are ordinary exports, so they're resolved straight
+// off atiadlxx.dll by name rather than through a QueryInterface indirection.
+type AdlMainControlCreateFn =
+    unsafe extern "stdcall" fn(AdlMainMemoryAllocFn, i32, *mut *mut c_void) -> i32;
+type AdlMainMemoryAllocFn = unsafe extern "stdcall" fn(i32) -> *mut c_void;
+type AdlMainControlDestroyFn = unsafe extern "stdcall" fn(*mut c_void) -> i32;
+type AdlOverdrive6CurrentStatusGetFn =
+    unsafe extern "stdcall" fn(*mut c_void, i32, *mut Adlod6CurrentStatus) -> i32;
+
+// Layout matches ADLOD6CurrentStatus from the ADL SDK's adl_structures.h.
+#[repr(C)]
+#[derive(Default)]
+struct Adlod6CurrentStatus {
+    size: i32,
+    engine_clock: i32,
+    memory_clock: i32,
+    gpu_activity_percent: i32,
+    current_performance_level: i32,
+    current_bus_speed: i32,
+    current_bus_lanes: i32,
+    max_bus_lanes: i32,
+    gpu_temperature: i32, // centi-degrees Celsius
+}
+
+// ADL asks its caller for an allocator at context-creation time; it only calls this
+// once or twice during `AdlApi::load`, so leaking the small buffer is harmless.
+unsafe extern "stdcall" fn adl_alloc(size: i32) -> *mut c_void {
+    let buf = vec![0u8; size.max(0) as usize].into_boxed_slice();
+    Box::into_raw(buf) as *mut c_void
+}
+
+// Thin wrapper over the handful of ADL entry points needed for per-adapter activity
+// and temperature.
+pub struct AdlApi {
+    context: *mut c_void,
+    overdrive6_current_status_get: AdlOverdrive6CurrentStatusGetFn,
+    main_control_destroy: AdlMainControlDestroyFn,
+}
+
+impl AdlApi {
+    // Loads atiadlxx.dll and creates an ADL context. Returns None if the DLL isn't
+    // present (e.g. non-AMD systems) or context creation fails, so callers can fall
+    // back to another backend.
+    pub unsafe fn load() -> Option<Self> {
+        let adl = LoadLibraryA(PCSTR(b"atiadlxx.dll\0".as_ptr())).ok()?;
+
+        let main_control_create: AdlMainControlCreateFn = std::mem::transmute(GetProcAddress(
+            adl,
+            PCSTR(b"ADL2_Main_Control_Create\0".as_ptr()),
+        )?);
+        let main_control_destroy: AdlMainControlDestroyFn = std::mem::transmute(GetProcAddress(
+            adl,
+            PCSTR(b"ADL2_Main_Control_Destroy\0".as_ptr()),
+        )?);
+        let overdrive6_current_status_get: AdlOverdrive6CurrentStatusGetFn =
+            std::mem::transmute(GetProcAddress(
+                adl,
+                PCSTR(b"ADL2_Overdrive6_CurrentStatus_Get\0".as_ptr()),
+            )?);
+
+        let mut context = std::ptr::null_mut();
+        if main_control_create(adl_alloc, 1, &mut context) != 0 || context.is_null() {
+            return None;
+        }
+
+        Some(AdlApi {
+            context,
+            overdrive6_current_status_get,
+            main_control_destroy,
+        })
+    }
+
+    // Returns activity percent and temperature for the adapter at `adapter_index`, in
+    // ADL's own enumeration order. ADL only enumerates AMD adapters, so callers must
+    // pass an AMD-only index, not a raw DXGI adapter index.
+    pub unsafe fn current_status(&self, adapter_index: i32) -> Option<AmdGpuUsage> {
+        let mut status = Adlod6CurrentStatus {
+            size: std::mem::size_of::<Adlod6CurrentStatus>() as i32,
+            ..Default::default()
+        };
+
+        if (self.overdrive6_current_status_get)(self.context, adapter_index, &mut status) != 0 {
+            return None;
+        }
+
+        Some(AmdGpuUsage {
+            utilization_percent: Some(status.gpu_activity_percent as f64),
+            temperature_c: Some(status.gpu_temperature as f64 / 100.0),
+            memory_used_bytes: None,
+        })
+    }
+}
+
+impl Drop for AdlApi {
+    fn drop(&mut self) {
+        unsafe {
+            (self.main_control_destroy)(self.context);
+        }
+    }
+}
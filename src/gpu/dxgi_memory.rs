@@ -0,0 +1,29 @@
+use windows::Win32::Graphics::Dxgi::*;
+
+/// Live VRAM usage for one adapter, summed across the local and non-local memory
+/// segment groups so unified-memory parts (e.g. integrated GPUs) are covered too.
+pub struct VideoMemoryUsage {
+    pub used: u64,
+    pub budget: u64,
+}
+
+// Queries DXGI 1.4's per-segment video memory info. Requires no vendor SDK, so it works
+// for NVIDIA, AMD and Intel adapters alike; only fails on pre-Windows-10 DXGI runtimes.
+pub unsafe fn query_video_memory_usage(adapter: &IDXGIAdapter) -> Option<VideoMemoryUsage> {
+    let adapter3: IDXGIAdapter3 = adapter.cast().ok()?;
+
+    let mut local = DXGI_QUERY_VIDEO_MEMORY_INFO::default();
+    adapter3
+        .QueryVideoMemoryInfo(0, DXGI_MEMORY_SEGMENT_GROUP_LOCAL, &mut local)
+        .ok()?;
+
+    let mut non_local = DXGI_QUERY_VIDEO_MEMORY_INFO::default();
+    adapter3
+        .QueryVideoMemoryInfo(0, DXGI_MEMORY_SEGMENT_GROUP_NON_LOCAL, &mut non_local)
+        .ok()?;
+
+    Some(VideoMemoryUsage {
+        used: local.CurrentUsage + non_local.CurrentUsage,
+        budget: local.Budget + non_local.Budget,
+    })
+}
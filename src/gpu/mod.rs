@@ -0,0 +1,216 @@
+#[cfg(windows)]
+use windows::Win32::Graphics::Dxgi::*;
+#[cfg(windows)]
+use windows::Win32::Graphics::Direct3D11::*;
+#[cfg(windows)]
+use windows::Win32::Foundation::*;
+
+mod amd;
+#[cfg(windows)]
+mod dxgi_memory;
+#[cfg(windows)]
+mod nvapi;
+#[cfg(windows)]
+use nvapi::NvApi;
+
+#[cfg(feature = "nvml")]
+mod nvml;
+
+/// Per-device metrics, harvested from whatever vendor backend is available: NVML
+/// (behind the `nvml` feature) for NVIDIA, amdgpu sysfs for AMD on Linux. Every field
+/// beyond `name` is optional because a given backend/driver/GPU combination may not
+/// expose it.
+#[derive(Debug, Clone, Default)]
+pub struct GpuStats {
+    pub name: String,
+    pub utilization_percent: Option<u32>,
+    pub memory_total_bytes: Option<u64>,
+    pub memory_used_bytes: Option<u64>,
+    pub memory_use_percent: Option<f64>,
+    pub temperature_c: Option<u32>,
+    pub power_mw: Option<u32>,
+}
+
+/// Per-process GPU attribution, as reported by a vendor backend (currently NVML only).
+/// Fields are optional because not every backend/driver combination exposes both
+/// memory and SM utilization for a given process.
+#[derive(Debug, Clone)]
+pub struct GpuProcInfo {
+    pub pid: u32,
+    pub used_memory_bytes: Option<u64>,
+    pub sm_utilization_percent: Option<u32>,
+}
+
+/// Cross-platform GPU stats, merging whatever backends are compiled in: NVML (behind
+/// the `nvml` feature) for NVIDIA, amdgpu sysfs for AMD on Linux. This is the entry
+/// point non-Windows targets use; Windows gets richer data from `get_gpu_report`.
+pub fn get_gpu_stats() -> Result<Vec<GpuStats>, Box<dyn std::error::Error>> {
+    #[allow(unused_mut)]
+    let mut stats = Vec::new();
+
+    #[cfg(feature = "nvml")]
+    stats.extend(nvml::get_gpu_stats()?);
+
+    #[cfg(target_os = "linux")]
+    stats.extend(amd::enumerate_linux().into_iter().map(|(index, usage)| GpuStats {
+        name: format!("AMD GPU {index}"),
+        utilization_percent: usage.utilization_percent.map(|p| p.round() as u32),
+        memory_total_bytes: None,
+        memory_used_bytes: usage.memory_used_bytes,
+        memory_use_percent: None,
+        temperature_c: usage.temperature_c.map(|t| t.round() as u32),
+        power_mw: None,
+    }));
+
+    Ok(stats)
+}
+
+/// Per-process GPU attribution. Only NVML implements this today; other backends (or
+/// builds without the `nvml` feature) report an empty vec rather than an error.
+pub fn get_process_gpu_usage() -> Vec<GpuProcInfo> {
+    #[cfg(feature = "nvml")]
+    {
+        nvml::get_process_gpu_usage()
+    }
+    #[cfg(not(feature = "nvml"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(windows)]
+const VENDOR_ID_NVIDIA: u32 = 0x10DE;
+#[cfg(windows)]
+const VENDOR_ID_AMD: u32 = 0x1002;
+#[cfg(windows)]
+const VENDOR_ID_INTEL: u32 = 0x8086;
+
+/// GPU silicon vendor, classified from `DXGI_ADAPTER_DESC::VendorId`. Only DXGI (Windows)
+/// populates this; other targets classify vendors differently (e.g. sysfs on Linux).
+#[cfg(windows)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuVendor {
+    Nvidia,
+    Amd,
+    Intel,
+    Unknown(u32),
+}
+
+#[cfg(windows)]
+impl GpuVendor {
+    fn from_vendor_id(vendor_id: u32) -> Self {
+        match vendor_id {
+            VENDOR_ID_NVIDIA => GpuVendor::Nvidia,
+            VENDOR_ID_AMD => GpuVendor::Amd,
+            VENDOR_ID_INTEL => GpuVendor::Intel,
+            other => GpuVendor::Unknown(other),
+        }
+    }
+}
+
+/// Stable, self-describing per-adapter report. Metric fields are `None` until a
+/// vendor-specific backend (NVAPI, NVML, ADL, ...) is able to populate them. Built from
+/// DXGI adapter enumeration, so only available on Windows; use `get_gpu_stats` there too.
+#[cfg(windows)]
+#[derive(Debug, Clone)]
+pub struct GpuReport {
+    pub index: u32,
+    pub name: String,
+    pub vendor: GpuVendor,
+    pub luid: String,
+    pub dedicated_video_memory: u64,
+    pub shared_memory: u64,
+    pub utilization: Option<f64>,
+    pub memory_used: Option<u64>,
+    pub memory_budget: Option<u64>,
+    pub temperature_c: Option<f64>,
+    pub power_w: Option<f64>,
+}
+
+// Enumerates DXGI adapters and builds a structured report per physical GPU. Video
+// memory comes from DXGI itself (works for any vendor); other metrics are filled in by
+// whatever vendor backend is available (NVAPI for NVIDIA core load; ADL for AMD
+// activity and temperature).
+// DXGI is a Windows-only API, so this entry point doesn't exist on other targets; use
+// `get_gpu_stats`/`get_process_gpu_usage` there instead.
+#[cfg(windows)]
+pub fn get_gpu_report() -> Result<Vec<GpuReport>, Box<dyn std::error::Error>> {
+    unsafe {
+        // Create DXGI Factory
+        let factory: IDXGIFactory = CreateDXGIFactory()?;
+
+        // NVIDIA cards can report real core load through NVAPI, enumerated once up front;
+        // AMD adapters are queried through the AMD backend below.
+        let nvapi = NvApi::load();
+        let nvapi_usages = nvapi.as_ref().map(|api| api.gpu_usages());
+
+        let mut reports = Vec::new();
+        let mut adapter_index = 0;
+        let mut nvidia_index = 0;
+        let mut amd_index = 0;
+
+        // Enumerate adapters
+        while let Ok(adapter) = factory.EnumAdapters(adapter_index) {
+            // Get adapter description
+            let mut desc = DXGI_ADAPTER_DESC::default();
+            adapter.GetDesc(&mut desc)?;
+
+            let name = String::from_utf16_lossy(&desc.Description)
+                .trim_end_matches('\0')
+                .to_string();
+            let vendor = GpuVendor::from_vendor_id(desc.VendorId);
+            let luid = format!("{:08x}{:08x}", desc.AdapterLuid.HighPart, desc.AdapterLuid.LowPart);
+
+            let utilization = if vendor == GpuVendor::Nvidia {
+                let usage = nvapi_usages
+                    .as_ref()
+                    .and_then(|usages| usages.get(nvidia_index))
+                    .copied();
+                nvidia_index += 1;
+                usage
+            } else {
+                None
+            };
+
+            // AMD has no analogue of NVAPI's QueryInterface trick, so its backend is
+            // queried per-adapter instead of enumerated once up front. ADL enumerates
+            // only AMD adapters, so (like `nvidia_index` above) it needs its own counter
+            // rather than the raw DXGI index, which also counts non-AMD adapters.
+            let amd_usage = if vendor == GpuVendor::Amd {
+                let usage = amd::query_usage(amd_index);
+                amd_index += 1;
+                usage
+            } else {
+                None
+            };
+
+            let video_memory = dxgi_memory::query_video_memory_usage(&adapter);
+
+            reports.push(GpuReport {
+                index: adapter_index as u32,
+                name,
+                vendor,
+                luid,
+                dedicated_video_memory: desc.DedicatedVideoMemory as u64,
+                shared_memory: desc.SharedSystemMemory as u64,
+                utilization: utilization.or(amd_usage.as_ref().and_then(|u| u.utilization_percent)),
+                memory_used: video_memory
+                    .as_ref()
+                    .map(|v| v.used)
+                    .or(amd_usage.as_ref().and_then(|u| u.memory_used_bytes)),
+                memory_budget: video_memory.as_ref().map(|v| v.budget),
+                temperature_c: amd_usage.as_ref().and_then(|u| u.temperature_c),
+                power_w: None,
+            });
+
+            adapter_index += 1;
+
+            // Limit to first few adapters to avoid infinite loop
+            if adapter_index >= 10 {
+                break;
+            }
+        }
+
+        Ok(reports)
+    }
+}
@@ -0,0 +1,78 @@
+use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryA};
+use windows::core::PCSTR;
+use std::ffi::c_void;
+
+// Undocumented NVAPI entry points, resolved at runtime through NvAPI_QueryInterface.
+// Offsets are stable across driver versions; see nvapi.h in the (non-redistributable) SDK.
+const NVAPI_INITIALIZE: u32 = 0x0150E828;
+const NVAPI_ENUM_PHYSICAL_GPUS: u32 = 0xE5AC921F;
+const NVAPI_GPU_GET_USAGES: u32 = 0x189A1FDF;
+const NVAPI_MAX_PHYSICAL_GPUS: usize = 64;
+const NVAPI_MAX_USAGES_PER_GPU: usize = 34;
+const NVAPI_GPU_USAGES_VER: u32 = 1;
+
+type QueryInterfaceFn = unsafe extern "C" fn(u32) -> *mut c_void;
+type InitializeFn = unsafe extern "C" fn() -> i32;
+type EnumPhysicalGpusFn =
+    unsafe extern "C" fn(*mut [*mut c_void; NVAPI_MAX_PHYSICAL_GPUS], *mut u32) -> i32;
+type GpuGetUsagesFn = unsafe extern "C" fn(*mut c_void, *mut u32) -> i32;
+
+// Thin wrapper over the handful of NVAPI entry points needed for per-GPU core load.
+pub struct NvApi {
+    enum_physical_gpus: EnumPhysicalGpusFn,
+    gpu_get_usages: GpuGetUsagesFn,
+}
+
+impl NvApi {
+    // Loads nvapi64.dll and resolves the entry points we need. Returns None if the
+    // driver/DLL isn't present (e.g. non-NVIDIA systems) so callers can fall back.
+    pub unsafe fn load() -> Option<Self> {
+        let nvapi = LoadLibraryA(PCSTR(b"nvapi64.dll\0".as_ptr())).ok()?;
+        let query_interface = GetProcAddress(nvapi, PCSTR(b"nvapi_QueryInterface\0".as_ptr()))?;
+        let query_interface: QueryInterfaceFn = std::mem::transmute(query_interface);
+
+        let initialize = query_interface(NVAPI_INITIALIZE);
+        let enum_physical_gpus = query_interface(NVAPI_ENUM_PHYSICAL_GPUS);
+        let gpu_get_usages = query_interface(NVAPI_GPU_GET_USAGES);
+        if initialize.is_null() || enum_physical_gpus.is_null() || gpu_get_usages.is_null() {
+            return None;
+        }
+
+        let initialize: InitializeFn = std::mem::transmute(initialize);
+        if initialize() != 0 {
+            return None;
+        }
+
+        Some(NvApi {
+            enum_physical_gpus: std::mem::transmute(enum_physical_gpus),
+            gpu_get_usages: std::mem::transmute(gpu_get_usages),
+        })
+    }
+
+    // Returns core load percent per physical GPU, in NVAPI enumeration order. A GPU whose
+    // usage query fails is reported as 0.0; an enumeration failure yields an empty vec.
+    pub unsafe fn gpu_usages(&self) -> Vec<f64> {
+        let mut handles: [*mut c_void; NVAPI_MAX_PHYSICAL_GPUS] =
+            [std::ptr::null_mut(); NVAPI_MAX_PHYSICAL_GPUS];
+        let mut count = 0u32;
+        if (self.enum_physical_gpus)(&mut handles, &mut count) != 0 {
+            return Vec::new();
+        }
+
+        handles[..count as usize]
+            .iter()
+            .map(|&handle| {
+                let mut usages = [0u32; NVAPI_MAX_USAGES_PER_GPU];
+                // MAKE_NVAPI_VERSION layout: struct size in the low 16 bits, version
+                // number in the high 16 bits.
+                usages[0] = (NVAPI_MAX_USAGES_PER_GPU as u32 * 4) | (NVAPI_GPU_USAGES_VER << 16);
+                if (self.gpu_get_usages)(handle, usages.as_mut_ptr()) != 0 {
+                    return 0.0;
+                }
+                // Index 3 is the documented core-load slot in NV_USAGES_INFO_V1; this
+                // is unverified against a real driver (no Windows/NVIDIA hardware in CI).
+                usages[3] as f64
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,112 @@
+use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+use nvml_wrapper::enums::device::UsedGpuMemory;
+use nvml_wrapper::Nvml;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+
+use super::{GpuProcInfo, GpuStats};
+
+static NVML: OnceCell<Option<Nvml>> = OnceCell::new();
+
+// NVML initialization is expensive (it spins up the driver's management API), so the
+// handle is created once and reused for every query.
+fn nvml() -> Option<&'static Nvml> {
+    NVML.get_or_init(|| Nvml::init().ok()).as_ref()
+}
+
+/// Queries NVML for every visible NVIDIA device. Returns an empty vec (rather than an
+/// error) when NVML isn't available, e.g. on a machine with no NVIDIA driver installed.
+pub fn get_gpu_stats() -> Result<Vec<GpuStats>, Box<dyn std::error::Error>> {
+    let Some(nvml) = nvml() else {
+        return Ok(Vec::new());
+    };
+
+    let count = nvml.device_count()?;
+    let mut stats = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+        let device = nvml.device_by_index(index)?;
+
+        let name = device.name().unwrap_or_else(|_| "Unknown".to_string());
+        let utilization_percent = device.utilization_rates().ok().map(|u| u.gpu);
+        let memory_info = device.memory_info().ok();
+        let memory_total_bytes = memory_info.as_ref().map(|m| m.total);
+        let memory_used_bytes = memory_info.as_ref().map(|m| m.used);
+        let memory_use_percent = memory_info
+            .as_ref()
+            .filter(|m| m.total > 0)
+            .map(|m| m.used as f64 / m.total as f64 * 100.0);
+        let temperature_c = device.temperature(TemperatureSensor::Gpu).ok();
+        let power_mw = device.power_usage().ok();
+
+        stats.push(GpuStats {
+            name,
+            utilization_percent,
+            memory_total_bytes,
+            memory_used_bytes,
+            memory_use_percent,
+            temperature_c,
+            power_mw,
+        });
+    }
+
+    Ok(stats)
+}
+
+// Merges compute/graphics process memory with per-pid SM utilization into one entry
+// per pid, across every visible device. Returns an empty vec if NVML is unavailable or
+// the device count can't be read; a device whose queries fail is skipped, rather than
+// letting one device's error hide every other's data.
+pub fn get_process_gpu_usage() -> Vec<GpuProcInfo> {
+    let Some(nvml) = nvml() else {
+        return Vec::new();
+    };
+
+    let Ok(count) = nvml.device_count() else {
+        return Vec::new();
+    };
+
+    let mut by_pid: HashMap<u32, GpuProcInfo> = HashMap::new();
+
+    for index in 0..count {
+        let Ok(device) = nvml.device_by_index(index) else {
+            continue;
+        };
+
+        for proc in device
+            .running_compute_processes()
+            .into_iter()
+            .flatten()
+            .chain(device.running_graphics_processes().into_iter().flatten())
+        {
+            let entry = by_pid.entry(proc.pid).or_insert_with(|| GpuProcInfo {
+                pid: proc.pid,
+                used_memory_bytes: None,
+                sm_utilization_percent: None,
+            });
+            if entry.used_memory_bytes.is_none() {
+                entry.used_memory_bytes = used_gpu_memory_bytes(&proc.used_gpu_memory);
+            }
+        }
+
+        if let Ok(samples) = device.process_utilization_stats(None) {
+            for sample in samples {
+                let entry = by_pid.entry(sample.pid).or_insert_with(|| GpuProcInfo {
+                    pid: sample.pid,
+                    used_memory_bytes: None,
+                    sm_utilization_percent: None,
+                });
+                entry.sm_utilization_percent = Some(sample.sm_util);
+            }
+        }
+    }
+
+    by_pid.into_values().collect()
+}
+
+fn used_gpu_memory_bytes(memory: &UsedGpuMemory) -> Option<u64> {
+    match memory {
+        UsedGpuMemory::Used(bytes) => Some(*bytes),
+        UsedGpuMemory::Unavailable => None,
+    }
+}
@@ -4,13 +4,86 @@ fn main() {
     // Example usage of GPU functions
     #[cfg(target_os = "windows")]
     {
-        match gpu::get_gpu_usage() {
-            Ok(usages) => {
-                for (i, &usage) in usages.iter().enumerate() {
-                    println!("GPU {} Usage: {:.2}%", i, usage);
+        match gpu::get_gpu_report() {
+            Ok(reports) => {
+                for report in &reports {
+                    match report.utilization {
+                        Some(usage) => println!(
+                            "GPU {} ({:?} {}): {:.2}%",
+                            report.index, report.vendor, report.name, usage
+                        ),
+                        None => println!(
+                            "GPU {} ({:?} {}): usage unavailable",
+                            report.index, report.vendor, report.name
+                        ),
+                    }
                 }
             }
             Err(e) => println!("Error getting GPU usage: {}", e),
         }
     }
+
+    // DXGI/NVAPI are Windows-only, so non-Windows targets fall back to the
+    // cross-platform backend: NVML (when built with the `nvml` feature) for NVIDIA,
+    // amdgpu sysfs for AMD on Linux.
+    #[cfg(not(target_os = "windows"))]
+    {
+        match gpu::get_gpu_stats() {
+            Ok(stats) => {
+                for stat in &stats {
+                    println!(
+                        "GPU {}: utilization {}, memory {}, temperature {}, power {}",
+                        stat.name,
+                        format_percent(stat.utilization_percent),
+                        format_memory(stat.memory_used_bytes, stat.memory_total_bytes, stat.memory_use_percent),
+                        format_temperature(stat.temperature_c),
+                        format_power(stat.power_mw),
+                    );
+                }
+            }
+            Err(e) => println!("Error getting GPU stats: {}", e),
+        }
+
+        for proc in gpu::get_process_gpu_usage() {
+            println!(
+                "  pid {}: memory {}, SM utilization {}",
+                proc.pid,
+                proc.used_memory_bytes
+                    .map(|bytes| format!("{bytes} bytes"))
+                    .unwrap_or_else(|| "unavailable".to_string()),
+                format_percent(proc.sm_utilization_percent),
+            );
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn format_percent(percent: Option<u32>) -> String {
+    percent
+        .map(|v| format!("{v}%"))
+        .unwrap_or_else(|| "unavailable".to_string())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn format_temperature(temperature_c: Option<u32>) -> String {
+    temperature_c
+        .map(|v| format!("{v}C"))
+        .unwrap_or_else(|| "unavailable".to_string())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn format_power(power_mw: Option<u32>) -> String {
+    power_mw
+        .map(|mw| format!("{:.1}W", mw as f64 / 1000.0))
+        .unwrap_or_else(|| "unavailable".to_string())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn format_memory(used_bytes: Option<u64>, total_bytes: Option<u64>, use_percent: Option<f64>) -> String {
+    match (used_bytes, total_bytes, use_percent) {
+        (Some(used), Some(total), Some(percent)) => format!("{used}/{total} bytes ({percent:.1}%)"),
+        (Some(used), Some(total), None) => format!("{used}/{total} bytes"),
+        (Some(used), None, _) => format!("{used} bytes used"),
+        _ => "unavailable".to_string(),
+    }
 }